@@ -15,15 +15,44 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Duration, Utc};
+use log::warn;
 use object_store::aws::AwsCredential;
 use object_store::gcp::GcpCredential;
 use pyo3::prelude::*;
-use std::sync::{Arc, RwLock};
-use tokio::sync::Mutex;
+use serde::Deserialize;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+use tokio::sync::{Mutex, RwLock};
 use aws_credential_types::provider::{ProvideCredentials, SharedCredentialsProvider};
+use zeroize::Zeroizing;
 
 const DEFAULT_REFRESH_CREDENTIALS_THRESHOLD: i64 = 600; // 10 minutes
 
+// Default clock-skew buffer subtracted from the GCP `expiration` field before it's treated as
+// the credential's hard expiry, to guard against drift between this process's clock and the
+// token-issuing server's.
+const DEFAULT_GCP_CLOCK_SKEW_BUFFER: i64 = 60; // 1 minute
+
+// Default OAuth scope requested from `generateAccessToken` when the caller doesn't specify one.
+const DEFAULT_IMPERSONATION_SCOPE: &str = "https://www.googleapis.com/auth/cloud-platform";
+
+/// Source of the current time, injected so tests can drive credentials to "within buffer" and
+/// "hard expired" states deterministically instead of sleeping against the real clock.
+trait TimeSource: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The default `TimeSource`, backed by the real wall clock.
+struct SystemTimeSource;
+
+impl TimeSource for SystemTimeSource {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
 /// Generic cached credential representation
 #[derive(Debug)]
 struct CredentialCache<C> {
@@ -31,31 +60,243 @@ struct CredentialCache<C> {
     expire_time: DateTime<Utc>,
 }
 
+// Zeroizing stand-in for `AwsCredential` while it lives in `ExpiringCache`; materialized into the
+// real type only at hand-off time.
+#[derive(Clone)]
+struct SecureAwsCredential {
+    key_id: String,
+    secret_key: Zeroizing<String>,
+    token: Option<Zeroizing<String>>,
+}
+
+impl From<&AwsCredential> for SecureAwsCredential {
+    fn from(credential: &AwsCredential) -> Self {
+        Self {
+            key_id: credential.key_id.clone(),
+            secret_key: Zeroizing::new(credential.secret_key.clone()),
+            token: credential.token.clone().map(Zeroizing::new),
+        }
+    }
+}
+
+impl SecureAwsCredential {
+    fn materialize(&self) -> AwsCredential {
+        AwsCredential {
+            key_id: self.key_id.clone(),
+            secret_key: self.secret_key.as_str().to_string(),
+            token: self.token.as_ref().map(|token| token.as_str().to_string()),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct SecureGcpCredential {
+    bearer: Zeroizing<String>,
+}
+
+impl From<&GcpCredential> for SecureGcpCredential {
+    fn from(credential: &GcpCredential) -> Self {
+        Self {
+            bearer: Zeroizing::new(credential.bearer.clone()),
+        }
+    }
+}
+
+impl SecureGcpCredential {
+    fn materialize(&self) -> GcpCredential {
+        GcpCredential {
+            bearer: self.bearer.as_str().to_string(),
+        }
+    }
+}
+
+// A generic expiring cache with built-in request de-duplication and non-blocking background
+// refresh. Replaces the hand-rolled fast-path/refresh-lock/double-check dance that used to be
+// copy-pasted across every credential provider.
+struct ExpiringCache<T: Clone> {
+    // How long before `expire_time` the value is considered stale and eligible for reload. Also
+    // doubles as the "nearing expiry" window that triggers a proactive background refresh.
+    buffer_time: Duration,
+    // The published value. Only ever write-locked briefly, to publish a freshly loaded value --
+    // never across a `loader` call -- so readers are never blocked behind an in-flight refresh.
+    value: Arc<RwLock<Option<(T, DateTime<Utc>)>>>,
+    // Coalesces concurrent refresh attempts into a single in-flight `loader` call. Held only
+    // for the duration of that call, and is a distinct lock from `value` for exactly this
+    // reason: a reader taking `value`'s read lock must never wait on a refresh in progress.
+    refresh_lock: Arc<Mutex<()>>,
+    time_source: Arc<dyn TimeSource>,
+    // Guards against spawning more than one background refresh at a time. `swap`-based
+    // CAS: whichever caller flips this from `false` to `true` owns the refresh and is
+    // responsible for clearing it afterwards.
+    refreshing: Arc<AtomicBool>,
+}
+
+impl<T: Clone + Send + Sync + 'static> ExpiringCache<T> {
+    fn new(buffer_time: Duration, time_source: Arc<dyn TimeSource>) -> Self {
+        Self {
+            buffer_time,
+            value: Arc::new(RwLock::new(None)),
+            refresh_lock: Arc::new(Mutex::new(())),
+            time_source,
+            refreshing: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    // Returns true if a cached `(value, expire_time)` pair is still fresh enough to serve
+    // without even scheduling a background refresh.
+    fn is_fresh(&self, expire_time: &DateTime<Utc>) -> bool {
+        self.time_source.now() < *expire_time - self.buffer_time
+    }
+
+    /// Returns the cached value if it's still fresh, otherwise loads a new one.
+    ///
+    /// Three cases, in order:
+    /// - Fresh: serve the cached value directly.
+    /// - Stale but not yet hard-expired (within `buffer_time` of `expire_time`): serve the
+    ///   still-valid cached value immediately and kick off at most one background refresh, so
+    ///   readers never pay the refresh latency at the expiry boundary.
+    /// - Hard-expired or never loaded: block and refresh inline, since there's nothing valid
+    ///   left to serve.
+    async fn get_or_load<F, Fut, E>(&self, loader: F) -> Result<T, E>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(T, DateTime<Utc>), E>> + Send + 'static,
+        E: std::fmt::Display + Send + 'static,
+    {
+        {
+            let cell = self.value.read().await;
+            if let Some((value, expire_time)) = cell.as_ref() {
+                if self.is_fresh(expire_time) {
+                    return Ok(value.clone());
+                }
+                if self.time_source.now() < *expire_time {
+                    let stale_value = value.clone();
+                    self.spawn_background_refresh(loader);
+                    return Ok(stale_value);
+                }
+            }
+        }
+
+        self.refresh(loader).await
+    }
+
+    // Spawns at most one in-flight background refresh. No-op if one is already running.
+    fn spawn_background_refresh<F, Fut, E>(&self, loader: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(T, DateTime<Utc>), E>> + Send + 'static,
+        E: std::fmt::Display + Send + 'static,
+    {
+        if self.refreshing.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let this = self.clone();
+        tokio::spawn(async move {
+            if let Err(err) = this.refresh(loader).await {
+                warn!("background credentials refresh failed: {}", err);
+            }
+            this.refreshing.store(false, Ordering::SeqCst);
+        });
+    }
+
+    // Coalesces concurrent refreshers behind `refresh_lock`, runs the loader with `value`
+    // unlocked so readers are never blocked by it, then briefly takes `value`'s write lock only
+    // to publish the result.
+    async fn refresh<F, Fut, E>(&self, loader: F) -> Result<T, E>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<(T, DateTime<Utc>), E>>,
+    {
+        let _refresh_guard = self.refresh_lock.lock().await;
+
+        // Another caller may have already refreshed while we waited for the guard.
+        let stale = {
+            let cell = self.value.read().await;
+            if let Some((value, expire_time)) = cell.as_ref() {
+                if self.is_fresh(expire_time) {
+                    return Ok(value.clone());
+                }
+            }
+            cell.clone()
+        };
+
+        match loader().await {
+            Ok((value, expire_time)) => {
+                let mut cell = self.value.write().await;
+                *cell = Some((value.clone(), expire_time));
+                Ok(value)
+            }
+            Err(err) => {
+                // Serve-stale-on-error: the refresh buffer (`buffer_time`) is a soft
+                // threshold, but `expire_time` is the hard, cryptographic expiry. If we
+                // still have a credential that hasn't actually expired, prefer it over
+                // propagating a transient failure (e.g. an identity-endpoint outage).
+                if let Some((value, expire_time)) = stale {
+                    if self.time_source.now() < expire_time {
+                        warn!(
+                            "credentials provider refresh failed, serving cached credential \
+                             until its hard expiry: {}",
+                            expire_time
+                        );
+                        return Ok(value);
+                    }
+                }
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<T: Clone> Clone for ExpiringCache<T> {
+    fn clone(&self) -> Self {
+        Self {
+            buffer_time: self.buffer_time,
+            value: Arc::clone(&self.value),
+            refresh_lock: Arc::clone(&self.refresh_lock),
+            time_source: Arc::clone(&self.time_source),
+            refreshing: Arc::clone(&self.refreshing),
+        }
+    }
+}
+
 // Core credential provider that handles shared logic for all cloud providers.
 // This struct contains all the common functionality for credential caching,
 // refreshing, and Python integration, avoiding code duplication.
 struct CoreCredentialsProvider {
     // Python credentials provider object
     py_provider: PyObject,
-    // Async mutex to coordinate credential refresh operations (prevents thundering herd)
-    refresh_lock: Arc<Mutex<()>>,
     // Time in seconds before expiration to trigger credential refresh
     refresh_threshold: i64,
+    // How long to wait for a Python `get_credentials`/`refresh_credentials` call before giving
+    // up. `None` preserves the old behavior of waiting forever.
+    refresh_timeout: Option<StdDuration>,
+    // Clock used for refresh/expiry decisions; overridden in tests to avoid wall-clock sleeps.
+    time_source: Arc<dyn TimeSource>,
 }
 
 impl CoreCredentialsProvider {
-    fn new(py_provider: PyObject, refresh_threshold: Option<i64>) -> Self {
+    fn new(
+        py_provider: PyObject,
+        refresh_threshold: Option<i64>,
+        refresh_timeout: Option<StdDuration>,
+        time_source: Option<Arc<dyn TimeSource>>,
+    ) -> Self {
         Self {
             py_provider,
-            refresh_lock: Arc::new(Mutex::new(())),
             refresh_threshold: refresh_threshold.unwrap_or(DEFAULT_REFRESH_CREDENTIALS_THRESHOLD),
+            refresh_timeout,
+            time_source: time_source.unwrap_or_else(|| Arc::new(SystemTimeSource)),
         }
     }
 
+    // The buffer `ExpiringCache` should treat credentials as stale ahead of their real expiry.
+    fn refresh_buffer(&self) -> Duration {
+        Duration::seconds(self.refresh_threshold)
+    }
+
     fn should_refresh(&self, expire_time: DateTime<Utc>) -> bool {
-        let now = Utc::now();
-        let threshold = Duration::seconds(self.refresh_threshold);
-        now > (expire_time - threshold)
+        let now = self.time_source.now();
+        now > (expire_time - self.refresh_buffer())
     }
 
     fn refresh_credentials(&self, py: Python) -> PyResult<()> {
@@ -68,30 +309,37 @@ impl CoreCredentialsProvider {
             })?;
         Ok(())
     }
-
-    async fn acquire_refresh_lock(&self) -> tokio::sync::MutexGuard<'_, ()> {
-        self.refresh_lock.lock().await
-    }
 }
 
 impl Clone for CoreCredentialsProvider {
     fn clone(&self) -> Self {
         Self {
             py_provider: Python::with_gil(|py| self.py_provider.clone_ref(py)),
-            refresh_lock: Arc::clone(&self.refresh_lock),
             refresh_threshold: self.refresh_threshold,
+            refresh_timeout: self.refresh_timeout,
+            time_source: Arc::clone(&self.time_source),
         }
     }
 }
 
-// Helper function to parse expiration time from RFC3339 string
-fn parse_expiration(expiration: Option<String>) -> DateTime<Utc> {
+// Helper function to parse expiration time from RFC3339 string, falling back to `time_source`
+// for the non-expiring/unparseable cases.
+fn parse_expiration(expiration: Option<String>, time_source: &dyn TimeSource) -> DateTime<Utc> {
     if let Some(exp_str) = expiration {
         DateTime::parse_from_rfc3339(&exp_str)
             .map(|dt| dt.with_timezone(&Utc))
-            .unwrap_or_else(|_| Utc::now() + Duration::hours(1))
+            .unwrap_or_else(|_| time_source.now() + Duration::hours(1))
     } else {
-        Utc::now() + Duration::days(365)
+        time_source.now() + Duration::days(365)
+    }
+}
+
+// Recognizes a Python credentials object that opts into anonymous/unsigned access via a
+// dedicated `is_anonymous` attribute. Providers without the attribute are never anonymous.
+fn py_is_anonymous(credentials: &PyObject, py: Python) -> PyResult<bool> {
+    match credentials.getattr(py, "is_anonymous") {
+        Ok(value) => value.extract::<bool>(py),
+        Err(_) => Ok(false),
     }
 }
 
@@ -117,6 +365,145 @@ fn py_err_to_object_store_error(e: PyErr) -> object_store::Error {
     }
 }
 
+// Error produced when a Python credentials provider doesn't respond within `refresh_timeout`.
+// Mirrors the `ProviderTimedOut` variant other AWS credential stacks use for the same failure.
+fn provider_timed_out_error(timeout: StdDuration) -> object_store::Error {
+    object_store::Error::Generic {
+        store: "credentials_provider",
+        source: Box::new(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            format!(
+                "credentials provider timed out after {} seconds",
+                timeout.as_secs_f64()
+            ),
+        )),
+    }
+}
+
+// Runs `fut` (a `spawn_blocking` join handle) under `timeout`, if one is configured. On elapse,
+// the join handle is simply dropped rather than awaited -- the underlying blocking task is
+// detached and left to finish on its own rather than leaked or forcibly cancelled.
+async fn with_refresh_timeout<T>(
+    timeout: Option<StdDuration>,
+    fut: tokio::task::JoinHandle<T>,
+) -> Result<T, object_store::Error>
+where
+    T: Send + 'static,
+{
+    match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, fut)
+            .await
+            .map_err(|_| provider_timed_out_error(timeout))?
+            .map_err(join_error_to_object_store_error),
+        None => fut.await.map_err(join_error_to_object_store_error),
+    }
+}
+
+// Same per-call timeout as `with_refresh_timeout`, but for driving an already-extracted
+// coroutine future to completion rather than joining a `spawn_blocking` handle.
+async fn with_coroutine_timeout<Fut>(
+    timeout: Option<StdDuration>,
+    fut: Fut,
+) -> object_store::Result<PyObject>
+where
+    Fut: Future<Output = PyResult<PyObject>>,
+{
+    match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, fut)
+            .await
+            .map_err(|_| provider_timed_out_error(timeout))?
+            .map_err(py_err_to_object_store_error),
+        None => fut.await.map_err(py_err_to_object_store_error),
+    }
+}
+
+// Converts a `PyErr` into an `io::Error`, for use inside the closures `FileCredentialStore`
+// passes through its own `io::Result`-returning API.
+fn py_err_to_io_error(e: PyErr) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::Other, e.to_string())
+}
+
+// The on-disk representation a `FileCredentialStore` reads/writes: the bearer token and its
+// parsed expiry, one per line.
+struct FileCachedCredential {
+    bearer: String,
+    expire_time: DateTime<Utc>,
+}
+
+impl FileCachedCredential {
+    fn serialize(&self) -> String {
+        format!("{}\n{}", self.expire_time.to_rfc3339(), self.bearer)
+    }
+
+    // The expiry is always a single RFC 3339 line; everything after the first newline is the
+    // bearer token verbatim, so a (realistically impossible) embedded newline still round-trips.
+    fn parse(contents: &str) -> Option<Self> {
+        let (expire_line, bearer) = contents.split_once('\n')?;
+        let expire_time = DateTime::parse_from_rfc3339(expire_line.trim())
+            .ok()?
+            .with_timezone(&Utc);
+        Some(Self {
+            bearer: bearer.to_string(),
+            expire_time,
+        })
+    }
+}
+
+// Cross-process credential cache backed by a single file under an advisory OS lock.
+struct FileCredentialStore {
+    path: std::path::PathBuf,
+}
+
+impl FileCredentialStore {
+    fn new(path: std::path::PathBuf) -> Self {
+        Self { path }
+    }
+
+    // Runs `refresh` under an exclusive file lock if the on-disk token is missing or within
+    // `refresh_buffer` of its expiry; otherwise adopts whatever another process already wrote.
+    fn get_or_refresh<F>(
+        &self,
+        now: DateTime<Utc>,
+        refresh_buffer: Duration,
+        refresh: F,
+    ) -> std::io::Result<FileCachedCredential>
+    where
+        F: FnOnce() -> std::io::Result<FileCachedCredential>,
+    {
+        let mut open_options = std::fs::OpenOptions::new();
+        open_options.create(true).read(true).write(true);
+        // The cached bearer token is sensitive, so keep the file readable only by its owner.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            open_options.mode(0o600);
+        }
+        let mut file = open_options.open(&self.path)?;
+        file.lock()?;
+
+        let result = (|| {
+            use std::io::{Read, Seek, SeekFrom, Write};
+
+            let mut contents = String::new();
+            file.read_to_string(&mut contents)?;
+            if let Some(cached) = FileCachedCredential::parse(&contents) {
+                if now < cached.expire_time - refresh_buffer {
+                    return Ok(cached);
+                }
+            }
+
+            let cached = refresh()?;
+            file.set_len(0)?;
+            file.seek(SeekFrom::Start(0))?;
+            file.write_all(cached.serialize().as_bytes())?;
+            Ok(cached)
+        })();
+
+        let _ = file.unlock();
+        result
+    }
+}
+
 // A credential provider that bridges Python credentials provider to Rust's object_store for AWS.
 // 
 // This provider wraps a Python credentials object and handles credential caching,
@@ -124,15 +511,15 @@ fn py_err_to_object_store_error(e: PyErr) -> object_store::Error {
 pub struct AwsCredentialsProvider {
     // Core logic shared across all providers
     core: Arc<CoreCredentialsProvider>,
-    // Thread-safe cache for the current AWS credentials
-    cached_credentials: Arc<RwLock<Option<CredentialCache<AwsCredential>>>>,
+    // De-duplicated, expiry-aware cache for the current AWS credentials, stored as a zeroizing `SecureAwsCredential`
+    cache: ExpiringCache<SecureAwsCredential>,
 }
 
 impl Clone for AwsCredentialsProvider {
     fn clone(&self) -> Self {
         Self {
             core: Arc::clone(&self.core),
-            cached_credentials: Arc::clone(&self.cached_credentials),
+            cache: self.cache.clone(),
         }
     }
 }
@@ -146,111 +533,126 @@ impl std::fmt::Debug for AwsCredentialsProvider {
 }
 
 impl AwsCredentialsProvider {
-    pub fn new(py_provider: PyObject, refresh_threshold: Option<i64>) -> Self {
-        Self {
-            core: Arc::new(CoreCredentialsProvider::new(py_provider, refresh_threshold)),
-            cached_credentials: Arc::new(RwLock::new(None)),
-        }
+    pub fn new(
+        py_provider: PyObject,
+        refresh_threshold: Option<i64>,
+        refresh_timeout: Option<StdDuration>,
+    ) -> Self {
+        Self::with_time_source(
+            py_provider,
+            refresh_threshold,
+            refresh_timeout,
+            Arc::new(SystemTimeSource),
+        )
+    }
+
+    // Test-only seam: lets tests inject a `TestTimeSource` instead of the real wall clock.
+    fn with_time_source(
+        py_provider: PyObject,
+        refresh_threshold: Option<i64>,
+        refresh_timeout: Option<StdDuration>,
+        time_source: Arc<dyn TimeSource>,
+    ) -> Self {
+        let core = Arc::new(CoreCredentialsProvider::new(
+            py_provider,
+            refresh_threshold,
+            refresh_timeout,
+            Some(time_source),
+        ));
+        let cache = ExpiringCache::new(core.refresh_buffer(), Arc::clone(&core.time_source));
+        Self { core, cache }
     }
 
     fn get_credentials(&self, py: Python) -> PyResult<CredentialCache<AwsCredential>> {
         let credentials = self.core.py_provider.call_method0(py, "get_credentials")?;
-        
+
         let access_key = credentials.getattr(py, "access_key")?.extract::<String>(py)?;
         let secret_key = credentials.getattr(py, "secret_key")?.extract::<String>(py)?;
         let token = credentials.getattr(py, "token")?.extract::<Option<String>>(py)?;
         let expiration = credentials.getattr(py, "expiration")?.extract::<Option<String>>(py)?;
-        
-        let expire_time = parse_expiration(expiration);
+
+        // Empty access/secret keys are AWS's own convention for "skip signing"; a provider can
+        // also opt in explicitly via `is_anonymous`. Either way, the bucket doesn't expect a
+        // real credential, so there's nothing to expire.
+        let is_anonymous =
+            (access_key.is_empty() && secret_key.is_empty()) || py_is_anonymous(&credentials, py)?;
+
+        let (credential, expire_time) = if is_anonymous {
+            (
+                AwsCredential {
+                    key_id: String::new(),
+                    secret_key: String::new(),
+                    token: None,
+                },
+                self.core.time_source.now() + Duration::days(365),
+            )
+        } else {
+            (
+                AwsCredential {
+                    key_id: access_key,
+                    secret_key,
+                    token,
+                },
+                parse_expiration(expiration, self.core.time_source.as_ref()),
+            )
+        };
 
         Ok(CredentialCache {
-            credential: Arc::new(AwsCredential {
-                key_id: access_key,
-                secret_key,
-                token,
-            }),
+            credential: Arc::new(credential),
             expire_time,
         })
     }
 }
 
 // Implements object_store's credential provider by delegating to MSC's Python credentials provider.
-// 
-// Uses a two-tier caching strategy with double-checked locking to minimize Python GIL
-// contention while ensuring credentials are refreshed before expiration.
+// Caching, refresh coordination and thread-safety are delegated to `ExpiringCache`.
 #[async_trait]
 impl object_store::CredentialProvider for AwsCredentialsProvider {
     type Credential = AwsCredential;
-    
+
     // Retrieves credentials from Python credentials provider, refreshing them if necessary.
     async fn get_credential(&self) -> object_store::Result<Arc<Self::Credential>> {
-        // Fast path: Check the cache without blocking
-        {
-            let cached_guard = self.cached_credentials.read().unwrap();
-            if let Some(cached_cred) = cached_guard.as_ref() {
-                if !self.core.should_refresh(cached_cred.expire_time) {
-                    return Ok(Arc::clone(&cached_cred.credential));
-                }
-            }
-        }
-        
-        // Acquire refresh lock to coordinate refresh (prevents thundering herd)
-        let _refresh_guard = self.core.acquire_refresh_lock().await;
-        
-        // Double-check: another thread might have refreshed while we waited
-        {
-            let cached_guard = self.cached_credentials.read().unwrap();
-            if let Some(cached_cred) = cached_guard.as_ref() {
-                if !self.core.should_refresh(cached_cred.expire_time) {
-                    return Ok(Arc::clone(&cached_cred.credential));
-                }
-            }
-        }
-        
-        // Spawn blocking task to refresh credentials
-        let cached_arc = Arc::clone(&self.cached_credentials);
-        let core = Arc::clone(&self.core);
         let this = self.clone();
-
-        tokio::task::spawn_blocking(move || {
-            Python::with_gil(|py| {
-                // Get the credentials from the Python credentials provider
-                let mut refreshed_credential = this.get_credentials(py)?;
-
-                // Check if the credentials need to be refreshed and refresh them if necessary
-                if core.should_refresh(refreshed_credential.expire_time) {
-                    core.refresh_credentials(py)?;
-                    refreshed_credential = this.get_credentials(py)?;
-                }
-                
-                // Create credential to return
-                let credential = AwsCredential {
-                    key_id: refreshed_credential.credential.key_id.clone(),
-                    secret_key: refreshed_credential.credential.secret_key.clone(),
-                    token: refreshed_credential.credential.token.clone(),
-                };
-                
-                // Update cache with write lock
-                {
-                    let mut cached_guard = cached_arc.write().unwrap();
-                    *cached_guard = Some(refreshed_credential);
+        let refresh_timeout = self.core.refresh_timeout;
+        self.cache
+            .get_or_load(move || {
+                let this = this.clone();
+                async move {
+                    let handle = tokio::task::spawn_blocking(move || {
+                        Python::with_gil(|py| {
+                            // Get the credentials from the Python credentials provider
+                            let mut refreshed_credential = this.get_credentials(py)?;
+
+                            // Check if the credentials need to be refreshed and refresh them if necessary
+                            if this.core.should_refresh(refreshed_credential.expire_time) {
+                                this.core.refresh_credentials(py)?;
+                                refreshed_credential = this.get_credentials(py)?;
+                            }
+
+                            let secure =
+                                SecureAwsCredential::from(refreshed_credential.credential.as_ref());
+                            Ok::<_, PyErr>((secure, refreshed_credential.expire_time))
+                        })
+                    });
+
+                    with_refresh_timeout(refresh_timeout, handle)
+                        .await?
+                        .map_err(py_err_to_object_store_error)
                 }
-                
-                Ok(credential)
             })
-        })
-        .await
-        .map_err(join_error_to_object_store_error)?
-        .map_err(py_err_to_object_store_error)
-        .map(Arc::new)
-        .map_err(Into::into)
+            .await
+            .map(|secure| Arc::new(secure.materialize()))
+            .map_err(Into::into)
     }
 }
 
 // Wrapper for AWS SDK credentials provider that implements object_store's CredentialProvider.
 // This allows using AWS SDK's default credential chain (environment variables, instance metadata, etc.)
+// Wraps the SDK chain with the same `ExpiringCache` the Python-backed providers use, instead of
+// re-invoking `provide_credentials()` on every object_store operation.
 pub struct AwsSdkCredentialsProvider {
     sdk_provider: SharedCredentialsProvider,
+    cache: ExpiringCache<SecureAwsCredential>,
 }
 
 impl std::fmt::Debug for AwsSdkCredentialsProvider {
@@ -260,8 +662,22 @@ impl std::fmt::Debug for AwsSdkCredentialsProvider {
 }
 
 impl AwsSdkCredentialsProvider {
-    pub fn new(sdk_provider: SharedCredentialsProvider) -> Self {
-        Self { sdk_provider }
+    pub fn new(sdk_provider: SharedCredentialsProvider, refresh_threshold: Option<i64>) -> Self {
+        Self::with_time_source(sdk_provider, refresh_threshold, Arc::new(SystemTimeSource))
+    }
+
+    // Test-only seam: lets tests inject a `TestTimeSource` instead of the real wall clock.
+    fn with_time_source(
+        sdk_provider: SharedCredentialsProvider,
+        refresh_threshold: Option<i64>,
+        time_source: Arc<dyn TimeSource>,
+    ) -> Self {
+        let buffer_time =
+            Duration::seconds(refresh_threshold.unwrap_or(DEFAULT_REFRESH_CREDENTIALS_THRESHOLD));
+        Self {
+            sdk_provider,
+            cache: ExpiringCache::new(buffer_time, time_source),
+        }
     }
 }
 
@@ -270,23 +686,63 @@ impl object_store::CredentialProvider for AwsSdkCredentialsProvider {
     type Credential = AwsCredential;
 
     async fn get_credential(&self) -> object_store::Result<Arc<Self::Credential>> {
-        let creds = self.sdk_provider
-            .provide_credentials()
-            .await
-            .map_err(|e| {
-                object_store::Error::Generic {
-                    store: "AwsSdkCredentialsProvider",
-                    source: Box::new(std::io::Error::new(
-                        std::io::ErrorKind::Other,
-                        format!("Failed to get AWS credentials: {}", e),
-                    )),
+        let sdk_provider = self.sdk_provider.clone();
+        self.cache
+            .get_or_load(move || {
+                let sdk_provider = sdk_provider.clone();
+                async move {
+                    let creds = sdk_provider.provide_credentials().await.map_err(|e| {
+                        object_store::Error::Generic {
+                            store: "AwsSdkCredentialsProvider",
+                            source: Box::new(std::io::Error::new(
+                                std::io::ErrorKind::Other,
+                                format!("Failed to get AWS credentials: {}", e),
+                            )),
+                        }
+                    })?;
+
+                    // A `None` expiry means the SDK considers these credentials non-expiring.
+                    let expire_time = creds
+                        .expiry()
+                        .map(DateTime::<Utc>::from)
+                        .unwrap_or_else(|| Utc::now() + Duration::days(365));
+
+                    let credential = AwsCredential {
+                        key_id: creds.access_key_id().to_string(),
+                        secret_key: creds.secret_access_key().to_string(),
+                        token: creds.session_token().map(|s| s.to_string()),
+                    };
+
+                    Ok((SecureAwsCredential::from(&credential), expire_time))
                 }
-            })?;
+            })
+            .await
+            .map(|secure| Arc::new(secure.materialize()))
+    }
+}
+
+// A credential provider for anonymous/unsigned access to public buckets.
+//
+// This is the distinct construction path for anonymity: it never touches Python and never
+// needs to refresh, since unsigned requests don't carry a credential that can expire.
+#[derive(Debug, Default)]
+pub struct AnonymousAwsCredentialsProvider;
+
+impl AnonymousAwsCredentialsProvider {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl object_store::CredentialProvider for AnonymousAwsCredentialsProvider {
+    type Credential = AwsCredential;
 
+    async fn get_credential(&self) -> object_store::Result<Arc<Self::Credential>> {
         Ok(Arc::new(AwsCredential {
-            key_id: creds.access_key_id().to_string(),
-            secret_key: creds.secret_access_key().to_string(),
-            token: creds.session_token().map(|s| s.to_string()),
+            key_id: String::new(),
+            secret_key: String::new(),
+            token: None,
         }))
     }
 }
@@ -295,15 +751,24 @@ impl object_store::CredentialProvider for AwsSdkCredentialsProvider {
 pub struct GcpCredentialsProvider {
     // Core logic shared across all providers
     core: Arc<CoreCredentialsProvider>,
-    // Thread-safe cache for the current GCP credentials
-    cached_credentials: Arc<RwLock<Option<CredentialCache<GcpCredential>>>>,
+    // De-duplicated, expiry-aware cache for the current GCP credentials. Stored as
+    // `SecureGcpCredential` so the bearer token is zeroized once this cache entry is replaced.
+    cache: ExpiringCache<SecureGcpCredential>,
+    // Subtracted from the parsed `expiration` field before it's used as the credential's hard
+    // expiry, to guard against clock skew between this process and the token-issuing server.
+    clock_skew: Duration,
+    // When set, shares the refreshed token with other processes through this file rather than
+    // each process independently refreshing against the token endpoint.
+    file_cache: Option<Arc<FileCredentialStore>>,
 }
 
 impl Clone for GcpCredentialsProvider {
     fn clone(&self) -> Self {
         Self {
             core: Arc::clone(&self.core),
-            cached_credentials: Arc::clone(&self.cached_credentials),
+            cache: self.cache.clone(),
+            clock_skew: self.clock_skew,
+            file_cache: self.file_cache.clone(),
         }
     }
 }
@@ -317,134 +782,459 @@ impl std::fmt::Debug for GcpCredentialsProvider {
 }
 
 impl GcpCredentialsProvider {
-    pub fn new(py_provider: PyObject, refresh_threshold: Option<i64>) -> Self {
+    pub fn new(
+        py_provider: PyObject,
+        refresh_threshold: Option<i64>,
+        refresh_timeout: Option<StdDuration>,
+        clock_skew_buffer: Option<i64>,
+        file_cache_path: Option<std::path::PathBuf>,
+    ) -> Self {
+        Self::with_time_source(
+            py_provider,
+            refresh_threshold,
+            refresh_timeout,
+            clock_skew_buffer,
+            file_cache_path,
+            Arc::new(SystemTimeSource),
+        )
+    }
+
+    // Test-only seam: lets tests inject a `TestTimeSource` instead of the real wall clock.
+    fn with_time_source(
+        py_provider: PyObject,
+        refresh_threshold: Option<i64>,
+        refresh_timeout: Option<StdDuration>,
+        clock_skew_buffer: Option<i64>,
+        file_cache_path: Option<std::path::PathBuf>,
+        time_source: Arc<dyn TimeSource>,
+    ) -> Self {
+        let core = Arc::new(CoreCredentialsProvider::new(
+            py_provider,
+            refresh_threshold,
+            refresh_timeout,
+            Some(time_source),
+        ));
+        let cache = ExpiringCache::new(core.refresh_buffer(), Arc::clone(&core.time_source));
+        let clock_skew = Duration::seconds(clock_skew_buffer.unwrap_or(DEFAULT_GCP_CLOCK_SKEW_BUFFER));
+        let file_cache = file_cache_path.map(|path| Arc::new(FileCredentialStore::new(path)));
         Self {
-            core: Arc::new(CoreCredentialsProvider::new(py_provider, refresh_threshold)),
-            cached_credentials: Arc::new(RwLock::new(None)),
+            core,
+            cache,
+            clock_skew,
+            file_cache,
         }
     }
 
     fn get_credentials(&self, py: Python) -> PyResult<CredentialCache<GcpCredential>> {
         let credentials = self.core.py_provider.call_method0(py, "get_credentials")?;
-        
-        // GCP Rust credentials provider requires a non-None bearer token
-        let token = credentials
-            .getattr(py, "token")?
-            .extract::<Option<String>>(py)?
-            .ok_or_else(|| {
-                PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    "GCP Rust credentials provider requires a non-None `token` string."
-                )
-            })?;
-        
-        let expiration = credentials.getattr(py, "expiration")?.extract::<Option<String>>(py)?;
-        
-        let expire_time = parse_expiration(expiration);
+        self.credential_cache_from_object(py, &credentials)
+    }
+
+    // Shared by both the synchronous path above and `call_provider_method`'s async path below:
+    // extracts a `CredentialCache` from an already-resolved Python credentials object.
+    fn credential_cache_from_object(
+        &self,
+        py: Python,
+        credentials: &PyObject,
+    ) -> PyResult<CredentialCache<GcpCredential>> {
+        // A provider can opt into anonymous/unsigned access via `is_anonymous`; in that case
+        // there's no bearer token to require, and nothing that will ever expire.
+        let is_anonymous = py_is_anonymous(credentials, py)?;
+
+        let token = credentials.getattr(py, "token")?.extract::<Option<String>>(py)?;
+
+        if !is_anonymous && token.is_none() {
+            // GCP Rust credentials provider requires a non-None bearer token
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "GCP Rust credentials provider requires a non-None `token` string.",
+            ));
+        }
+
+        let expire_time = if is_anonymous {
+            self.core.time_source.now() + Duration::days(365)
+        } else {
+            let expiration = credentials.getattr(py, "expiration")?.extract::<Option<String>>(py)?;
+            // Subtract the clock-skew buffer so a server clock that's slightly ahead of ours
+            // doesn't leave us serving a bearer token the server already considers expired.
+            parse_expiration(expiration, self.core.time_source.as_ref()) - self.clock_skew
+        };
 
         Ok(CredentialCache {
             credential: Arc::new(GcpCredential {
-                bearer: token,
+                bearer: token.unwrap_or_default(),
             }),
             expire_time,
         })
     }
+
+    // Calls a zero-arg method on a blocking thread, then drives the result to completion via
+    // pyo3-asyncio if it's a coroutine (an `async def` implementation) rather than a plain value.
+    async fn call_provider_method(&self, method: &'static str) -> object_store::Result<PyObject> {
+        let py_provider = Python::with_gil(|py| self.core.py_provider.clone_ref(py));
+        let handle =
+            tokio::task::spawn_blocking(move || Python::with_gil(|py| py_provider.call_method0(py, method)));
+        let result = with_refresh_timeout(self.core.refresh_timeout, handle)
+            .await?
+            .map_err(py_err_to_object_store_error)?;
+
+        let is_awaitable =
+            Python::with_gil(|py| result.as_ref(py).hasattr("__await__").unwrap_or(false));
+        if !is_awaitable {
+            return Ok(result);
+        }
+
+        let future = Python::with_gil(|py| pyo3_asyncio::tokio::into_future(result.as_ref(py)))
+            .map_err(py_err_to_object_store_error)?;
+        with_coroutine_timeout(self.core.refresh_timeout, future).await
+    }
 }
 
 // Implements object_store's credential provider for GCP by delegating to MSC's Python credentials provider.
-// 
-// Uses a two-tier caching strategy with double-checked locking to minimize Python GIL
-// contention while ensuring credentials are refreshed before expiration.
+// Caching, refresh coordination and thread-safety are delegated to `ExpiringCache`.
 #[async_trait]
 impl object_store::CredentialProvider for GcpCredentialsProvider {
     type Credential = GcpCredential;
-    
+
     // Retrieves GCP credentials from Python credentials provider, refreshing them if necessary.
     async fn get_credential(&self) -> object_store::Result<Arc<Self::Credential>> {
-        // Fast path: Check the cache without blocking
-        {
-            let cached_guard = self.cached_credentials.read().unwrap();
-            if let Some(cached_cred) = cached_guard.as_ref() {
-                if !self.core.should_refresh(cached_cred.expire_time) {
-                    return Ok(Arc::clone(&cached_cred.credential));
-                }
-            }
-        }
-        
-        // Acquire refresh lock to coordinate refresh (prevents thundering herd)
-        let _refresh_guard = self.core.acquire_refresh_lock().await;
-        
-        // Double-check: another thread might have refreshed while we waited
-        {
-            let cached_guard = self.cached_credentials.read().unwrap();
-            if let Some(cached_cred) = cached_guard.as_ref() {
-                if !self.core.should_refresh(cached_cred.expire_time) {
-                    return Ok(Arc::clone(&cached_cred.credential));
-                }
-            }
-        }
-        
-        // Spawn blocking task to refresh credentials
-        let cached_arc = Arc::clone(&self.cached_credentials);
-        let core = Arc::clone(&self.core);
         let this = self.clone();
-
-        tokio::task::spawn_blocking(move || {
-            Python::with_gil(|py| {
-                // Get the credentials from the Python credentials provider
-                let mut refreshed_credential = this.get_credentials(py)?;
-
-                // Check if the credentials need to be refreshed and refresh them if necessary
-                if core.should_refresh(refreshed_credential.expire_time) {
-                    core.refresh_credentials(py)?;
-                    refreshed_credential = this.get_credentials(py)?;
-                }
-                
-                // Return the refreshed credentials and cache them
-                let credential = GcpCredential {
-                    bearer: refreshed_credential.credential.bearer.clone(),
-                };
-                
-                // Update cache with write lock
-                {
-                    let mut cached_guard = cached_arc.write().unwrap();
-                    *cached_guard = Some(refreshed_credential);
+        let refresh_timeout = self.core.refresh_timeout;
+        self.cache
+            .get_or_load(move || {
+                let this = this.clone();
+                async move {
+                    // With a file cache configured, defer to it so that other processes sharing
+                    // the file only pay for a refresh once, the same way `ExpiringCache` already
+                    // does for threads within this process. File-cache-backed providers are
+                    // assumed synchronous for now.
+                    if let Some(file_cache) = this.file_cache.clone() {
+                        let this = this.clone();
+                        let handle = tokio::task::spawn_blocking(move || {
+                            Python::with_gil(|py| {
+                                let now = this.core.time_source.now();
+                                let cached = file_cache
+                                    .get_or_refresh(now, this.core.refresh_buffer(), || {
+                                        let mut refreshed_credential =
+                                            this.get_credentials(py).map_err(py_err_to_io_error)?;
+                                        if this.core.should_refresh(refreshed_credential.expire_time) {
+                                            this.core
+                                                .refresh_credentials(py)
+                                                .map_err(py_err_to_io_error)?;
+                                            refreshed_credential =
+                                                this.get_credentials(py).map_err(py_err_to_io_error)?;
+                                        }
+                                        Ok(FileCachedCredential {
+                                            bearer: refreshed_credential.credential.bearer.clone(),
+                                            expire_time: refreshed_credential.expire_time,
+                                        })
+                                    })
+                                    .map_err(|e| {
+                                        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+                                            e.to_string(),
+                                        )
+                                    })?;
+
+                                let secure = SecureGcpCredential::from(&GcpCredential {
+                                    bearer: cached.bearer,
+                                });
+                                Ok::<_, PyErr>((secure, cached.expire_time))
+                            })
+                        });
+
+                        return with_refresh_timeout(refresh_timeout, handle)
+                            .await?
+                            .map_err(py_err_to_object_store_error);
+                    }
+
+                    // Otherwise, support both a synchronous and an `async def` Python provider:
+                    // `call_provider_method` runs the call on a blocking thread so a synchronous
+                    // implementation's blocking I/O doesn't stall the async runtime, and drives
+                    // an `async def` implementation's coroutine to completion via pyo3-asyncio.
+                    let credentials_obj = this.call_provider_method("get_credentials").await?;
+                    let mut refreshed_credential = Python::with_gil(|py| {
+                        this.credential_cache_from_object(py, &credentials_obj)
+                    })
+                    .map_err(py_err_to_object_store_error)?;
+
+                    if this.core.should_refresh(refreshed_credential.expire_time) {
+                        let _ = this.call_provider_method("refresh_credentials").await?;
+                        let credentials_obj = this.call_provider_method("get_credentials").await?;
+                        refreshed_credential = Python::with_gil(|py| {
+                            this.credential_cache_from_object(py, &credentials_obj)
+                        })
+                        .map_err(py_err_to_object_store_error)?;
+                    }
+
+                    let secure = SecureGcpCredential::from(refreshed_credential.credential.as_ref());
+                    Ok((secure, refreshed_credential.expire_time))
                 }
-                
-                Ok(credential)
             })
-        })
-        .await
-        .map_err(join_error_to_object_store_error)?
-        .map_err(py_err_to_object_store_error)
-        .map(Arc::new)
-        .map_err(Into::into)
+            .await
+            .map(|secure| Arc::new(secure.materialize()))
+            .map_err(Into::into)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::sync::atomic::{AtomicUsize, Ordering};
-    use std::sync::Once;
+// The fields of IAM Credentials' `generateAccessToken` response that we care about.
+#[derive(Deserialize)]
+struct GenerateAccessTokenResponse {
+    #[serde(rename = "accessToken")]
+    access_token: String,
+    #[serde(rename = "expireTime")]
+    expire_time: String,
+}
 
-    static INIT: Once = Once::new();
+// Abstraction over the `generateAccessToken` network call, so tests can substitute a fake
+// instead of making a real HTTP request -- mirrors how `TimeSource` lets tests avoid the real
+// wall clock. Returns the minted `(access_token, expire_time)` pair, `expire_time` as RFC 3339.
+#[async_trait]
+trait AccessTokenExchanger: Send + Sync {
+    async fn exchange(
+        &self,
+        bearer_token: &str,
+        target_service_account: &str,
+        scopes: &[String],
+    ) -> object_store::Result<(String, String)>;
+}
 
-    // Initialize Python interpreter once for all tests
-    fn initialize_python() {
-        INIT.call_once(|| {
-            pyo3::prepare_freethreaded_python();
-        });
+// Real `AccessTokenExchanger`, backed by IAM Credentials' `generateAccessToken` endpoint.
+struct IamCredentialsExchanger {
+    http_client: reqwest::Client,
+}
+
+impl Default for IamCredentialsExchanger {
+    fn default() -> Self {
+        Self {
+            http_client: reqwest::Client::new(),
+        }
     }
+}
 
-    // Mock Python credentials object with attributes
-    #[pyclass]
-    struct MockCredentials {
-        #[pyo3(get)]
-        access_key: String,
-        #[pyo3(get)]
-        secret_key: String,
-        #[pyo3(get)]
-        token: Option<String>,
+#[async_trait]
+impl AccessTokenExchanger for IamCredentialsExchanger {
+    async fn exchange(
+        &self,
+        bearer_token: &str,
+        target_service_account: &str,
+        scopes: &[String],
+    ) -> object_store::Result<(String, String)> {
+        let url = format!(
+            "https://iamcredentials.googleapis.com/v1/projects/-/serviceAccounts/{}:generateAccessToken",
+            target_service_account
+        );
+
+        let response = self
+            .http_client
+            .post(&url)
+            .bearer_auth(bearer_token)
+            .json(&serde_json::json!({ "scope": scopes }))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|source| object_store::Error::Generic {
+                store: "GcpImpersonationCredentialsProvider",
+                source: Box::new(source),
+            })?;
+
+        let body: GenerateAccessTokenResponse =
+            response.json().await.map_err(|source| object_store::Error::Generic {
+                store: "GcpImpersonationCredentialsProvider",
+                source: Box::new(source),
+            })?;
+
+        Ok((body.access_token, body.expire_time))
+    }
+}
+
+// Mints short-lived impersonated access tokens for a target service account via IAM Credentials'
+// `generateAccessToken` endpoint, reusing `GcpCredentialsProvider`'s caching and refresh machinery.
+#[derive(Clone)]
+pub struct GcpImpersonationCredentialsProvider {
+    // Base credential, used to authenticate the `generateAccessToken` call itself.
+    base: GcpCredentialsProvider,
+    target_service_account: String,
+    scopes: Vec<String>,
+    exchanger: Arc<dyn AccessTokenExchanger>,
+    // De-duplicated, expiry-aware cache for the impersonated token, independent of `base`'s cache.
+    cache: ExpiringCache<SecureGcpCredential>,
+}
+
+impl std::fmt::Debug for GcpImpersonationCredentialsProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GcpImpersonationCredentialsProvider")
+            .field("target_service_account", &self.target_service_account)
+            .finish()
+    }
+}
+
+impl GcpImpersonationCredentialsProvider {
+    pub fn new(
+        py_provider: PyObject,
+        target_service_account: String,
+        scopes: Option<Vec<String>>,
+        refresh_threshold: Option<i64>,
+        refresh_timeout: Option<StdDuration>,
+    ) -> Self {
+        Self::with_exchanger(
+            py_provider,
+            target_service_account,
+            scopes,
+            refresh_threshold,
+            refresh_timeout,
+            Arc::new(IamCredentialsExchanger::default()),
+            Arc::new(SystemTimeSource),
+        )
+    }
+
+    // Test-only seam: lets tests substitute a fake `AccessTokenExchanger` and a `TestTimeSource`
+    // instead of making a real network call against the real wall clock.
+    fn with_exchanger(
+        py_provider: PyObject,
+        target_service_account: String,
+        scopes: Option<Vec<String>>,
+        refresh_threshold: Option<i64>,
+        refresh_timeout: Option<StdDuration>,
+        exchanger: Arc<dyn AccessTokenExchanger>,
+        time_source: Arc<dyn TimeSource>,
+    ) -> Self {
+        // `None` leaves the default clock-skew buffer in place (IAM-issued tokens are just as
+        // subject to clock drift as any other GCP credential); there's no cross-process file
+        // cache to configure here.
+        let base = GcpCredentialsProvider::with_time_source(
+            py_provider,
+            refresh_threshold,
+            refresh_timeout,
+            None,
+            None,
+            Arc::clone(&time_source),
+        );
+        let buffer_time = base.core.refresh_buffer();
+        Self {
+            base,
+            target_service_account,
+            scopes: scopes.unwrap_or_else(|| vec![DEFAULT_IMPERSONATION_SCOPE.to_string()]),
+            exchanger,
+            cache: ExpiringCache::new(buffer_time, time_source),
+        }
+    }
+
+    // Calls IAM Credentials' `generateAccessToken` endpoint using the base provider's bearer
+    // token, minting a short-lived impersonated access token for `target_service_account`.
+    async fn generate_access_token(&self) -> object_store::Result<(SecureGcpCredential, DateTime<Utc>)> {
+        let base_credential = self.base.get_credential().await?;
+
+        let (access_token, expire_time) = self
+            .exchanger
+            .exchange(&base_credential.bearer, &self.target_service_account, &self.scopes)
+            .await?;
+
+        let expire_time = DateTime::parse_from_rfc3339(&expire_time)
+            .map_err(|source| object_store::Error::Generic {
+                store: "GcpImpersonationCredentialsProvider",
+                source: Box::new(source),
+            })?
+            .with_timezone(&Utc);
+
+        let credential = GcpCredential { bearer: access_token };
+        Ok((SecureGcpCredential::from(&credential), expire_time))
+    }
+}
+
+#[async_trait]
+impl object_store::CredentialProvider for GcpImpersonationCredentialsProvider {
+    type Credential = GcpCredential;
+
+    async fn get_credential(&self) -> object_store::Result<Arc<Self::Credential>> {
+        let this = self.clone();
+        self.cache
+            .get_or_load(move || {
+                let this = this.clone();
+                async move { this.generate_access_token().await }
+            })
+            .await
+            .map(|secure| Arc::new(secure.materialize()))
+            .map_err(Into::into)
+    }
+}
+
+// A credential provider for anonymous/unsigned access to public GCS buckets.
+//
+// This is the distinct construction path for anonymity: it never touches Python and never
+// needs to refresh, since unsigned requests don't carry a credential that can expire.
+#[derive(Debug, Default)]
+pub struct AnonymousGcpCredentialsProvider;
+
+impl AnonymousGcpCredentialsProvider {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl object_store::CredentialProvider for AnonymousGcpCredentialsProvider {
+    type Credential = GcpCredential;
+
+    async fn get_credential(&self) -> object_store::Result<Arc<Self::Credential>> {
+        Ok(Arc::new(GcpCredential {
+            bearer: String::new(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Once;
+    use std::sync::RwLock;
+
+    static INIT: Once = Once::new();
+
+    // Initialize Python interpreter once for all tests
+    fn initialize_python() {
+        INIT.call_once(|| {
+            pyo3::prepare_freethreaded_python();
+        });
+    }
+
+    // A `TimeSource` that can be advanced manually, so tests can drive credentials to the
+    // "within buffer" and "hard expired" states deterministically instead of sleeping.
+    struct TestTimeSource {
+        now: RwLock<DateTime<Utc>>,
+    }
+
+    impl TestTimeSource {
+        fn new(now: DateTime<Utc>) -> Arc<Self> {
+            Arc::new(Self {
+                now: RwLock::new(now),
+            })
+        }
+
+        fn set(&self, now: DateTime<Utc>) {
+            *self.now.write().unwrap() = now;
+        }
+
+        fn advance(&self, delta: Duration) {
+            let mut now = self.now.write().unwrap();
+            *now = *now + delta;
+        }
+    }
+
+    impl TimeSource for TestTimeSource {
+        fn now(&self) -> DateTime<Utc> {
+            *self.now.read().unwrap()
+        }
+    }
+
+    // Mock Python credentials object with attributes
+    #[pyclass]
+    struct MockCredentials {
+        #[pyo3(get)]
+        access_key: String,
+        #[pyo3(get)]
+        secret_key: String,
+        #[pyo3(get)]
+        token: Option<String>,
         #[pyo3(get)]
         expiration: Option<String>,
     }
@@ -528,6 +1318,51 @@ mod tests {
         }
     }
 
+    // Mock provider whose `refresh_credentials` simulates a slow identity-endpoint round trip,
+    // for tests that need a refresh to still be in flight when other callers show up.
+    #[pyclass]
+    struct MockSlowRefreshCredentialsProvider {
+        access_key: String,
+        secret_key: String,
+        expiration: Arc<RwLock<Option<String>>>,
+        refresh_count: Arc<AtomicUsize>,
+    }
+
+    #[pymethods]
+    impl MockSlowRefreshCredentialsProvider {
+        #[new]
+        fn new(access_key: String, secret_key: String, expiration: Option<String>) -> Self {
+            Self {
+                access_key,
+                secret_key,
+                expiration: Arc::new(RwLock::new(expiration)),
+                refresh_count: Arc::new(AtomicUsize::new(0)),
+            }
+        }
+
+        fn get_credentials(&self, py: Python) -> PyResult<PyObject> {
+            let expiration = self.expiration.read().unwrap().clone();
+            Ok(create_mock_credentials(
+                py,
+                &self.access_key,
+                &self.secret_key,
+                None,
+                expiration.as_deref(),
+            ))
+        }
+
+        fn refresh_credentials(&self) {
+            std::thread::sleep(StdDuration::from_millis(200));
+            self.refresh_count.fetch_add(1, Ordering::SeqCst);
+            let new_expiration = (Utc::now() + Duration::hours(1)).to_rfc3339();
+            *self.expiration.write().unwrap() = Some(new_expiration);
+        }
+
+        fn get_refresh_count(&self) -> usize {
+            self.refresh_count.load(Ordering::SeqCst)
+        }
+    }
+
     #[test]
     fn test_cached_credential_creation() {
         let credential = Arc::new(AwsCredential {
@@ -561,7 +1396,7 @@ mod tests {
             )
             .unwrap();
 
-            let provider = AwsCredentialsProvider::new(mock_provider.into(), Some(900));
+            let provider = AwsCredentialsProvider::new(mock_provider.into(), Some(900), None);
 
             let expire_time = Utc::now() - Duration::hours(1);
 
@@ -570,6 +1405,38 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_should_refresh_with_injected_time_source() {
+        initialize_python();
+        Python::with_gil(|py| {
+            let mock_provider = Py::new(
+                py,
+                MockCredentialsProvider::new("access".to_string(), "secret".to_string(), None, None),
+            )
+            .unwrap();
+
+            let start = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc);
+            let time_source = TestTimeSource::new(start);
+            let provider = AwsCredentialsProvider::with_time_source(
+                mock_provider.into(),
+                Some(900), // 15 minute refresh buffer
+                None,
+                time_source.clone(),
+            );
+
+            let expire_time = start + Duration::minutes(30);
+
+            // Outside the refresh buffer: no refresh needed yet.
+            assert!(!provider.core.should_refresh(expire_time));
+
+            // Advance the clock to inside the buffer without touching the real clock.
+            time_source.advance(Duration::minutes(20));
+            assert!(provider.core.should_refresh(expire_time));
+        });
+    }
+
     #[test]
     fn test_get_credentials_from_python() {
         initialize_python();
@@ -585,7 +1452,7 @@ mod tests {
             )
             .unwrap();
 
-            let provider = AwsCredentialsProvider::new(mock_provider.into(), None);
+            let provider = AwsCredentialsProvider::new(mock_provider.into(), None, None);
             let result = provider.get_credentials(py);
 
             assert!(result.is_ok());
@@ -595,7 +1462,29 @@ mod tests {
             assert_eq!(cached.credential.token, Some("test_token".to_string()));
         });
     }
-    
+
+    #[test]
+    fn test_aws_get_credentials_anonymous_via_empty_keys() {
+        initialize_python();
+        Python::with_gil(|py| {
+            let mock_provider = Py::new(
+                py,
+                MockCredentialsProvider::new(String::new(), String::new(), None, None),
+            )
+            .unwrap();
+
+            let provider = AwsCredentialsProvider::new(mock_provider.into(), None, None);
+            let result = provider.get_credentials(py);
+
+            assert!(result.is_ok());
+            let cached = result.unwrap();
+            assert_eq!(cached.credential.key_id, "");
+            assert_eq!(cached.credential.secret_key, "");
+            // Anonymous credentials are treated as never needing a refresh.
+            assert!(cached.expire_time > Utc::now() + Duration::days(1));
+        });
+    }
+
     #[test]
     fn test_refresh_credentials_succeeds() {
         initialize_python();
@@ -611,7 +1500,7 @@ mod tests {
             )
             .unwrap();
 
-            let provider = AwsCredentialsProvider::new(mock_provider.into(), None);
+            let provider = AwsCredentialsProvider::new(mock_provider.into(), None, None);
             
             // Call refresh_credentials through core which should succeed
             let result = provider.core.refresh_credentials(py);
@@ -644,7 +1533,7 @@ mod tests {
             )
             .unwrap();
             
-            let provider = Arc::new(AwsCredentialsProvider::new(mock_provider_obj.clone_ref(py).into(), Some(0)));
+            let provider = Arc::new(AwsCredentialsProvider::new(mock_provider_obj.clone_ref(py).into(), Some(0), None));
             
             (mock_provider_obj, provider)
         });
@@ -683,6 +1572,220 @@ mod tests {
         });
     }
 
+    #[tokio::test]
+    async fn test_background_refresh_serves_stale_value_without_blocking() {
+        use object_store::CredentialProvider;
+
+        initialize_python();
+
+        let start = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let (mock_provider_obj, provider, time_source) = Python::with_gil(|py| {
+            let mock_provider_obj = Py::new(
+                py,
+                MockCredentialsProvider::new(
+                    "near_expiry_access".to_string(),
+                    "near_expiry_secret".to_string(),
+                    Some("near_expiry_token".to_string()),
+                    Some((start + Duration::minutes(10)).to_rfc3339()),
+                ),
+            )
+            .unwrap();
+
+            let time_source = TestTimeSource::new(start);
+            let provider = AwsCredentialsProvider::with_time_source(
+                mock_provider_obj.clone_ref(py).into(),
+                Some(300), // 5 minute refresh buffer
+                None,
+                time_source.clone(),
+            );
+
+            (mock_provider_obj, provider, time_source)
+        });
+
+        // Prime the cache with the initial credential; still well outside the refresh buffer.
+        let first = provider.get_credential().await.unwrap();
+        assert_eq!(first.key_id, "near_expiry_access");
+
+        // Advance into the refresh buffer (5 minutes before the 10-minute expiry) but short of
+        // the hard expiry: the call should return the still-valid cached credential immediately
+        // instead of blocking on a refresh, while kicking off exactly one background refresh.
+        time_source.advance(Duration::minutes(6));
+        let second = provider.get_credential().await.unwrap();
+        assert_eq!(second.key_id, "near_expiry_access");
+
+        // Give the background refresh task a chance to run.
+        tokio::time::sleep(StdDuration::from_millis(50)).await;
+
+        Python::with_gil(|py| {
+            let refresh_count = mock_provider_obj.borrow(py).get_refresh_count();
+            assert_eq!(
+                refresh_count, 1,
+                "background refresh should have fired exactly once"
+            );
+        });
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_callers_not_blocked_by_in_flight_background_refresh() {
+        use object_store::CredentialProvider;
+
+        initialize_python();
+
+        let start = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let (mock_provider_obj, provider, time_source) = Python::with_gil(|py| {
+            let mock_provider_obj = Py::new(
+                py,
+                MockSlowRefreshCredentialsProvider::new(
+                    "near_expiry_access".to_string(),
+                    "near_expiry_secret".to_string(),
+                    Some((start + Duration::minutes(10)).to_rfc3339()),
+                ),
+            )
+            .unwrap();
+
+            let time_source = TestTimeSource::new(start);
+            let provider = AwsCredentialsProvider::with_time_source(
+                mock_provider_obj.clone_ref(py).into(),
+                Some(300), // 5 minute refresh buffer
+                None,
+                time_source.clone(),
+            );
+
+            (mock_provider_obj, provider, time_source)
+        });
+
+        // Prime the cache, then advance into the refresh buffer so the next call serves the
+        // stale value and kicks off a background refresh that sleeps for 200ms.
+        let _ = provider.get_credential().await.unwrap();
+        time_source.advance(Duration::minutes(6));
+        let _ = provider.get_credential().await.unwrap();
+
+        // While that refresh is in flight, a burst of concurrent callers should all be served
+        // immediately from the fast path rather than stalling behind the refresh's write lock.
+        tokio::time::sleep(StdDuration::from_millis(20)).await;
+        let deadline = tokio::time::Instant::now() + StdDuration::from_millis(100);
+        let calls = (0..10).map(|_| {
+            let provider = provider.clone();
+            tokio::spawn(async move { provider.get_credential().await })
+        });
+        for call in calls {
+            let result = tokio::time::timeout_at(deadline, call)
+                .await
+                .expect("concurrent caller should not be blocked by the in-flight background refresh")
+                .unwrap()
+                .unwrap();
+            assert_eq!(result.key_id, "near_expiry_access");
+        }
+
+        // Let the background refresh finish and confirm it only ran once.
+        tokio::time::sleep(StdDuration::from_millis(300)).await;
+        Python::with_gil(|py| {
+            assert_eq!(mock_provider_obj.borrow(py).get_refresh_count(), 1);
+        });
+    }
+
+    // Fake `ProvideCredentials` impl for `AwsSdkCredentialsProvider` tests, mirroring
+    // `MockCredentialsProvider`'s call-counter pattern.
+    #[derive(Debug)]
+    struct FakeAwsSdkCredentialsProvider {
+        access_key: String,
+        secret_key: String,
+        expiry: Option<std::time::SystemTime>,
+        call_count: Arc<AtomicUsize>,
+    }
+
+    impl ProvideCredentials for FakeAwsSdkCredentialsProvider {
+        fn provide_credentials<'a>(&'a self) -> aws_credential_types::provider::future::ProvideCredentials<'a>
+        where
+            Self: 'a,
+        {
+            self.call_count.fetch_add(1, Ordering::SeqCst);
+            let access_key = self.access_key.clone();
+            let secret_key = self.secret_key.clone();
+            let expiry = self.expiry;
+            aws_credential_types::provider::future::ProvideCredentials::new(async move {
+                Ok(aws_credential_types::Credentials::new(
+                    access_key,
+                    secret_key,
+                    None,
+                    expiry,
+                    "fake",
+                ))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_aws_sdk_credentials_provider_caches_until_expiry() {
+        use object_store::CredentialProvider;
+
+        let start = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let expiry = std::time::SystemTime::UNIX_EPOCH
+            + StdDuration::from_secs((start + Duration::minutes(10)).timestamp() as u64);
+
+        let call_count = Arc::new(AtomicUsize::new(0));
+        let fake = FakeAwsSdkCredentialsProvider {
+            access_key: "AKIA_TEST".to_string(),
+            secret_key: "secret_test".to_string(),
+            expiry: Some(expiry),
+            call_count: call_count.clone(),
+        };
+
+        let time_source = TestTimeSource::new(start);
+        let provider = AwsSdkCredentialsProvider::with_time_source(
+            SharedCredentialsProvider::new(fake),
+            Some(300), // 5 minute refresh buffer
+            time_source.clone(),
+        );
+
+        let first = provider.get_credential().await.unwrap();
+        assert_eq!(first.key_id, "AKIA_TEST");
+        assert_eq!(call_count.load(Ordering::SeqCst), 1);
+
+        // Still before the refresh buffer: served from cache without calling the SDK chain again.
+        let second = provider.get_credential().await.unwrap();
+        assert_eq!(second.key_id, "AKIA_TEST");
+        assert_eq!(call_count.load(Ordering::SeqCst), 1);
+
+        // Advance past the hard expiry: the next call should re-invoke the SDK chain once.
+        time_source.advance(Duration::minutes(11));
+        let _ = provider.get_credential().await.unwrap();
+        assert_eq!(call_count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_aws_sdk_credentials_provider_non_expiring_fallback() {
+        use object_store::CredentialProvider;
+
+        let call_count = Arc::new(AtomicUsize::new(0));
+        let fake = FakeAwsSdkCredentialsProvider {
+            access_key: "AKIA_TEST".to_string(),
+            secret_key: "secret_test".to_string(),
+            expiry: None,
+            call_count: call_count.clone(),
+        };
+
+        let provider = AwsSdkCredentialsProvider::new(SharedCredentialsProvider::new(fake), None);
+
+        let credential = provider.get_credential().await.unwrap();
+        assert_eq!(credential.key_id, "AKIA_TEST");
+        assert_eq!(credential.secret_key, "secret_test");
+        assert_eq!(call_count.load(Ordering::SeqCst), 1);
+
+        // No expiry reported by the SDK: the non-expiring fallback should keep serving the
+        // cached credential on a subsequent call rather than re-invoking the SDK chain.
+        let _ = provider.get_credential().await.unwrap();
+        assert_eq!(call_count.load(Ordering::SeqCst), 1);
+    }
+
     // GCP-specific tests: focus on token field extraction and None token error handling
     #[pyclass]
     struct MockGcpCredentials {
@@ -696,28 +1799,104 @@ mod tests {
     #[pyclass]
     struct MockGcpCredentialsProvider {
         token: Option<String>,
-        expiration: Option<String>,
+        expiration: Arc<RwLock<Option<String>>>,
+        refresh_count: Arc<AtomicUsize>,
     }
 
     #[pymethods]
     impl MockGcpCredentialsProvider {
         #[new]
         fn new(token: Option<String>, expiration: Option<String>) -> Self {
-            Self { token, expiration }
+            Self {
+                token,
+                expiration: Arc::new(RwLock::new(expiration)),
+                refresh_count: Arc::new(AtomicUsize::new(0)),
+            }
         }
 
         fn get_credentials(&self, py: Python) -> PyResult<PyObject> {
+            let expiration = self.expiration.read().unwrap().clone();
             Py::new(
                 py,
                 MockGcpCredentials {
                     token: self.token.clone(),
-                    expiration: self.expiration.clone(),
+                    expiration,
                 },
             )
             .map(|obj| obj.into())
         }
 
-        fn refresh_credentials(&self) {}
+        fn refresh_credentials(&self) {
+            self.refresh_count.fetch_add(1, Ordering::SeqCst);
+            let new_expiration = (Utc::now() + Duration::hours(1)).to_rfc3339();
+            *self.expiration.write().unwrap() = Some(new_expiration);
+        }
+
+        fn get_refresh_count(&self) -> usize {
+            self.refresh_count.load(Ordering::SeqCst)
+        }
+    }
+
+    /// Mock GCP credentials provider whose `get_credentials`/`refresh_credentials` are `async
+    /// def` coroutines instead of plain methods, mirroring `MockGcpCredentialsProvider`.
+    #[pyclass]
+    struct MockAsyncGcpCredentialsProvider {
+        token: Option<String>,
+        expiration: Arc<RwLock<Option<String>>>,
+        refresh_count: Arc<AtomicUsize>,
+    }
+
+    #[pymethods]
+    impl MockAsyncGcpCredentialsProvider {
+        #[new]
+        fn new(token: Option<String>, expiration: Option<String>) -> Self {
+            Self {
+                token,
+                expiration: Arc::new(RwLock::new(expiration)),
+                refresh_count: Arc::new(AtomicUsize::new(0)),
+            }
+        }
+
+        fn get_credentials<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+            let token = self.token.clone();
+            let expiration = self.expiration.read().unwrap().clone();
+            pyo3_asyncio::tokio::future_into_py(py, async move {
+                Python::with_gil(|py| -> PyResult<PyObject> {
+                    Py::new(py, MockGcpCredentials { token, expiration }).map(|obj| obj.into())
+                })
+            })
+        }
+
+        fn refresh_credentials<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+            self.refresh_count.fetch_add(1, Ordering::SeqCst);
+            let new_expiration = (Utc::now() + Duration::hours(1)).to_rfc3339();
+            *self.expiration.write().unwrap() = Some(new_expiration);
+            pyo3_asyncio::tokio::future_into_py(py, async move { Python::with_gil(|py| Ok(py.None())) })
+        }
+
+        fn get_refresh_count(&self) -> usize {
+            self.refresh_count.load(Ordering::SeqCst)
+        }
+    }
+
+    // Mock async GCP provider whose `get_credentials` coroutine never resolves, for testing
+    // that `refresh_timeout` still applies to the async path.
+    #[pyclass]
+    struct MockHangingAsyncGcpCredentialsProvider;
+
+    #[pymethods]
+    impl MockHangingAsyncGcpCredentialsProvider {
+        #[new]
+        fn new() -> Self {
+            Self
+        }
+
+        fn get_credentials<'py>(&self, py: Python<'py>) -> PyResult<&'py PyAny> {
+            pyo3_asyncio::tokio::future_into_py(py, async move {
+                std::future::pending::<()>().await;
+                Python::with_gil(|py| Ok(py.None()))
+            })
+        }
     }
 
     #[test]
@@ -733,7 +1912,7 @@ mod tests {
             )
             .unwrap();
 
-            let provider = GcpCredentialsProvider::new(mock_provider.into(), None);
+            let provider = GcpCredentialsProvider::new(mock_provider.into(), None, None, None, None);
             let result = provider.get_credentials(py);
 
             assert!(result.is_ok());
@@ -742,6 +1921,325 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_gcp_expire_time_accounts_for_clock_skew() {
+        initialize_python();
+        Python::with_gil(|py| {
+            let mock_provider = Py::new(
+                py,
+                MockGcpCredentialsProvider::new(
+                    Some("ya29.test_access_token".to_string()),
+                    Some("2025-12-31T23:59:59Z".to_string()),
+                ),
+            )
+            .unwrap();
+
+            let provider = GcpCredentialsProvider::new(mock_provider.into(), None, None, Some(30), None);
+            let cached = provider.get_credentials(py).unwrap();
+
+            let expected_expiry = DateTime::parse_from_rfc3339("2025-12-31T23:59:59Z")
+                .unwrap()
+                .with_timezone(&Utc)
+                - Duration::seconds(30);
+            assert_eq!(cached.expire_time, expected_expiry);
+        });
+    }
+
+    #[tokio::test]
+    async fn test_gcp_refresh_triggers_once_skew_adjusted_expiry_passes() {
+        use object_store::CredentialProvider;
+
+        initialize_python();
+
+        let start = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let (mock_provider_obj, provider, time_source) = Python::with_gil(|py| {
+            let mock_provider_obj = Py::new(
+                py,
+                MockGcpCredentialsProvider::new(
+                    Some("ya29.test_access_token".to_string()),
+                    Some((start + Duration::seconds(90)).to_rfc3339()),
+                ),
+            )
+            .unwrap();
+
+            let time_source = TestTimeSource::new(start);
+            let provider = GcpCredentialsProvider::with_time_source(
+                mock_provider_obj.clone_ref(py).into(),
+                Some(0), // no proactive refresh buffer; only the hard expiry matters here
+                None,
+                Some(60), // 60 second clock-skew buffer
+                None,
+                time_source.clone(),
+            );
+
+            (mock_provider_obj, provider, time_source)
+        });
+
+        // Skew-adjusted expiry is `start + 90s - 60s = start + 30s`; at `start` it's still valid.
+        let _ = provider.get_credential().await.unwrap();
+        Python::with_gil(|py| {
+            assert_eq!(mock_provider_obj.borrow(py).get_refresh_count(), 0);
+        });
+
+        // Advance past the skew-adjusted expiry: the cached value is now hard-expired, so the
+        // next call should block on a refresh rather than serving it.
+        time_source.advance(Duration::seconds(31));
+        let _ = provider.get_credential().await.unwrap();
+        Python::with_gil(|py| {
+            assert_eq!(
+                mock_provider_obj.borrow(py).get_refresh_count(),
+                1,
+                "refresh should trigger once the skew-adjusted expiry passes"
+            );
+        });
+    }
+
+    #[tokio::test]
+    async fn test_gcp_async_provider_get_credentials() {
+        use object_store::CredentialProvider;
+
+        initialize_python();
+
+        let mock_provider_obj = Python::with_gil(|py| {
+            Py::new(
+                py,
+                MockAsyncGcpCredentialsProvider::new(
+                    Some("ya29.async_access_token".to_string()),
+                    Some("2025-12-31T23:59:59Z".to_string()),
+                ),
+            )
+            .unwrap()
+        });
+
+        let provider = Python::with_gil(|py| {
+            GcpCredentialsProvider::new(mock_provider_obj.clone_ref(py).into(), None, None, None, None)
+        });
+
+        let credential = provider.get_credential().await.unwrap();
+        assert_eq!(credential.bearer, "ya29.async_access_token");
+    }
+
+    #[tokio::test]
+    async fn test_gcp_async_provider_refreshes_on_expiry() {
+        use object_store::CredentialProvider;
+
+        initialize_python();
+
+        let start = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let (mock_provider_obj, provider, time_source) = Python::with_gil(|py| {
+            let mock_provider_obj = Py::new(
+                py,
+                MockAsyncGcpCredentialsProvider::new(
+                    Some("ya29.async_access_token".to_string()),
+                    Some((start + Duration::seconds(30)).to_rfc3339()),
+                ),
+            )
+            .unwrap();
+
+            let time_source = TestTimeSource::new(start);
+            let provider = GcpCredentialsProvider::with_time_source(
+                mock_provider_obj.clone_ref(py).into(),
+                Some(0), // no proactive refresh buffer; only the hard expiry matters here
+                None,
+                Some(0), // no clock-skew buffer
+                None,
+                time_source.clone(),
+            );
+
+            (mock_provider_obj, provider, time_source)
+        });
+
+        let _ = provider.get_credential().await.unwrap();
+        Python::with_gil(|py| {
+            assert_eq!(mock_provider_obj.borrow(py).get_refresh_count(), 0);
+        });
+
+        time_source.advance(Duration::seconds(31));
+        let _ = provider.get_credential().await.unwrap();
+        Python::with_gil(|py| {
+            assert_eq!(
+                mock_provider_obj.borrow(py).get_refresh_count(),
+                1,
+                "an async provider's coroutine-returning refresh_credentials should still run"
+            );
+        });
+    }
+
+    #[tokio::test]
+    async fn test_gcp_async_provider_coroutine_respects_refresh_timeout() {
+        use object_store::CredentialProvider;
+
+        initialize_python();
+
+        let mock_provider_obj = Python::with_gil(|py| {
+            Py::new(py, MockHangingAsyncGcpCredentialsProvider::new()).unwrap()
+        });
+
+        let provider = Python::with_gil(|py| {
+            GcpCredentialsProvider::new(
+                mock_provider_obj.clone_ref(py).into(),
+                None,
+                Some(StdDuration::from_millis(50)),
+                None,
+                None,
+            )
+        });
+
+        let result = tokio::time::timeout(StdDuration::from_secs(5), provider.get_credential()).await;
+        assert!(
+            matches!(result, Ok(Err(_))),
+            "a hanging `async def get_credentials` coroutine should time out rather than block forever"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_gcp_file_cache_shares_credential_across_providers() {
+        use object_store::CredentialProvider;
+        use std::sync::atomic::AtomicU32;
+
+        initialize_python();
+
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let mut file_cache_path = std::env::temp_dir();
+        file_cache_path.push(format!(
+            "msc_test_gcp_file_cache_{}_{}.txt",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        let _ = std::fs::remove_file(&file_cache_path);
+
+        let start = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let time_source = TestTimeSource::new(start);
+
+        // Two independent providers, standing in for two separate processes, sharing one
+        // file-backed cache.
+        // Mock token TTL is comfortably longer than the default 10 minute refresh buffer, so
+        // the shared on-disk entry is still outside its proactive-refresh window when B reads it.
+        let provider_a = Python::with_gil(|py| {
+            let mock_provider = Py::new(
+                py,
+                MockGcpCredentialsProvider::new(
+                    Some("process_a_token".to_string()),
+                    Some((start + Duration::minutes(30)).to_rfc3339()),
+                ),
+            )
+            .unwrap();
+            GcpCredentialsProvider::with_time_source(
+                mock_provider.into(),
+                None,
+                None,
+                Some(0),
+                Some(file_cache_path.clone()),
+                time_source.clone(),
+            )
+        });
+        let provider_b = Python::with_gil(|py| {
+            let mock_provider = Py::new(
+                py,
+                MockGcpCredentialsProvider::new(
+                    Some("process_b_token".to_string()),
+                    Some((start + Duration::minutes(30)).to_rfc3339()),
+                ),
+            )
+            .unwrap();
+            GcpCredentialsProvider::with_time_source(
+                mock_provider.into(),
+                None,
+                None,
+                Some(0),
+                Some(file_cache_path.clone()),
+                time_source.clone(),
+            )
+        });
+
+        // Provider A refreshes first and writes its token to the shared file.
+        let credential_a = provider_a.get_credential().await.unwrap();
+        assert_eq!(credential_a.bearer, "process_a_token");
+
+        // Provider B observes the still-fresh on-disk token and adopts it rather than calling
+        // into its own (distinct) Python provider.
+        let credential_b = provider_b.get_credential().await.unwrap();
+        assert_eq!(
+            credential_b.bearer, "process_a_token",
+            "provider B should adopt the token provider A already wrote to the shared file"
+        );
+
+        let _ = std::fs::remove_file(&file_cache_path);
+    }
+
+    #[tokio::test]
+    async fn test_gcp_file_cache_refreshes_within_buffer_not_just_at_hard_expiry() {
+        use object_store::CredentialProvider;
+        use std::sync::atomic::AtomicU32;
+
+        initialize_python();
+
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let mut file_cache_path = std::env::temp_dir();
+        file_cache_path.push(format!(
+            "msc_test_gcp_file_cache_buffer_{}_{}.txt",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        let _ = std::fs::remove_file(&file_cache_path);
+
+        let start = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let time_source = TestTimeSource::new(start);
+
+        let (mock_provider_obj, provider) = Python::with_gil(|py| {
+            let mock_provider_obj = Py::new(
+                py,
+                MockGcpCredentialsProvider::new(
+                    Some("initial_token".to_string()),
+                    Some((start + Duration::minutes(10)).to_rfc3339()),
+                ),
+            )
+            .unwrap();
+            let provider = GcpCredentialsProvider::with_time_source(
+                mock_provider_obj.clone_ref(py).into(),
+                Some(300), // 5 minute refresh buffer
+                None,
+                Some(0),
+                Some(file_cache_path.clone()),
+                time_source.clone(),
+            );
+            (mock_provider_obj, provider)
+        });
+
+        let first = provider.get_credential().await.unwrap();
+        assert_eq!(first.bearer, "initial_token");
+
+        // Advance into the refresh buffer (5 minutes before the 10-minute expiry) but short of
+        // the hard expiry: the on-disk entry should be refreshed now rather than left untouched
+        // until it's fully hard-expired.
+        time_source.advance(Duration::minutes(6));
+        let _ = provider.get_credential().await.unwrap();
+
+        // The call above serves the still-valid cached value and only spawns a background
+        // refresh; yield to the runtime so that refresh actually runs before we check it.
+        tokio::time::sleep(StdDuration::from_millis(300)).await;
+
+        Python::with_gil(|py| {
+            assert_eq!(
+                mock_provider_obj.borrow(py).get_refresh_count(),
+                1,
+                "file-cache-backed provider should refresh proactively within the buffer window"
+            );
+        });
+
+        let _ = std::fs::remove_file(&file_cache_path);
+    }
+
     #[test]
     fn test_gcp_none_token_error() {
         initialize_python();
@@ -752,7 +2250,7 @@ mod tests {
             )
             .unwrap();
 
-            let provider = GcpCredentialsProvider::new(mock_provider.into(), None);
+            let provider = GcpCredentialsProvider::new(mock_provider.into(), None, None, None, None);
             let result = provider.get_credentials(py);
 
             assert!(result.is_err());
@@ -765,5 +2263,142 @@ mod tests {
         });
     }
 
+    // Fake `AccessTokenExchanger` for `GcpImpersonationCredentialsProvider` tests: records what
+    // it was called with instead of making a real network call.
+    struct FakeAccessTokenExchanger {
+        access_token: String,
+        expire_time: String,
+        call_count: Arc<AtomicUsize>,
+        last_bearer_token: Arc<RwLock<Option<String>>>,
+        last_target_service_account: Arc<RwLock<Option<String>>>,
+    }
+
+    #[async_trait]
+    impl AccessTokenExchanger for FakeAccessTokenExchanger {
+        async fn exchange(
+            &self,
+            bearer_token: &str,
+            target_service_account: &str,
+            _scopes: &[String],
+        ) -> object_store::Result<(String, String)> {
+            self.call_count.fetch_add(1, Ordering::SeqCst);
+            *self.last_bearer_token.write().unwrap() = Some(bearer_token.to_string());
+            *self.last_target_service_account.write().unwrap() = Some(target_service_account.to_string());
+            Ok((self.access_token.clone(), self.expire_time.clone()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_gcp_impersonation_none_token_error() {
+        use object_store::CredentialProvider;
+
+        initialize_python();
+
+        let mock_provider_obj =
+            Python::with_gil(|py| Py::new(py, MockGcpCredentialsProvider::new(None, None)).unwrap());
+
+        let provider = Python::with_gil(|py| {
+            GcpImpersonationCredentialsProvider::new(
+                mock_provider_obj.clone_ref(py).into(),
+                "target@project.iam.gserviceaccount.com".to_string(),
+                None,
+                None,
+                None,
+            )
+        });
+
+        let result = provider.get_credential().await;
+        assert!(result.is_err());
+        let err_msg = result.unwrap_err().to_string();
+        assert!(
+            err_msg.contains("non-None `token` string"),
+            "Error should explain token requirement, got: {}",
+            err_msg
+        );
+    }
+
+    #[tokio::test]
+    async fn test_gcp_impersonation_calls_generate_access_token_with_base_bearer_token() {
+        use object_store::CredentialProvider;
+
+        initialize_python();
+
+        let start = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let mock_provider_obj = Python::with_gil(|py| {
+            Py::new(
+                py,
+                MockGcpCredentialsProvider::new(
+                    Some("base_bearer_token".to_string()),
+                    Some((start + Duration::minutes(30)).to_rfc3339()),
+                ),
+            )
+            .unwrap()
+        });
+
+        let call_count = Arc::new(AtomicUsize::new(0));
+        let last_bearer_token = Arc::new(RwLock::new(None));
+        let last_target_service_account = Arc::new(RwLock::new(None));
+        let exchanger = Arc::new(FakeAccessTokenExchanger {
+            access_token: "impersonated_token".to_string(),
+            expire_time: (start + Duration::minutes(10)).to_rfc3339(),
+            call_count: call_count.clone(),
+            last_bearer_token: last_bearer_token.clone(),
+            last_target_service_account: last_target_service_account.clone(),
+        });
+
+        let time_source = TestTimeSource::new(start);
+        let provider = Python::with_gil(|py| {
+            GcpImpersonationCredentialsProvider::with_exchanger(
+                mock_provider_obj.clone_ref(py).into(),
+                "target@project.iam.gserviceaccount.com".to_string(),
+                None,
+                Some(300), // 5 minute refresh buffer
+                None,
+                exchanger,
+                time_source.clone(),
+            )
+        });
+
+        let credential = provider.get_credential().await.unwrap();
+        assert_eq!(credential.bearer, "impersonated_token");
+        assert_eq!(call_count.load(Ordering::SeqCst), 1);
+        assert_eq!(last_bearer_token.read().unwrap().as_deref(), Some("base_bearer_token"));
+        assert_eq!(
+            last_target_service_account.read().unwrap().as_deref(),
+            Some("target@project.iam.gserviceaccount.com")
+        );
+
+        // Served from cache on a subsequent call before the refresh buffer, without re-invoking the exchanger.
+        let _ = provider.get_credential().await.unwrap();
+        assert_eq!(call_count.load(Ordering::SeqCst), 1);
+
+        // Advance past the impersonated token's expiry: the next call should mint a fresh one.
+        time_source.advance(Duration::minutes(11));
+        let _ = provider.get_credential().await.unwrap();
+        assert_eq!(call_count.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_providers_return_unsigned_sentinel() {
+        use object_store::CredentialProvider;
+
+        let aws_credential = AnonymousAwsCredentialsProvider::new()
+            .get_credential()
+            .await
+            .unwrap();
+        assert_eq!(aws_credential.key_id, "");
+        assert_eq!(aws_credential.secret_key, "");
+        assert_eq!(aws_credential.token, None);
+
+        let gcp_credential = AnonymousGcpCredentialsProvider::new()
+            .get_credential()
+            .await
+            .unwrap();
+        assert_eq!(gcp_credential.bearer, "");
+    }
+
 }
 